@@ -2,12 +2,17 @@ use anyhow::{bail, Context, Result};
 use clap::{ArgAction, Parser, Subcommand};
 use console::style;
 use dialoguer::{theme::ColorfulTheme, Confirm, FuzzySelect, Input};
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use regex::Regex;
 use reqwest::blocking::Client;
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
 use serde::Deserialize;
 use serde_json::{json, Value};
+use service_manager::{
+    ServiceLabel, ServiceManager, ServiceStartCtx, ServiceStatus, ServiceStatusCtx, ServiceStopCtx,
+};
 use std::env;
-use std::io::{self, Read};
+use std::io::{self, BufRead, BufReader, Read};
 use std::process::{Command, Stdio};
 use std::thread;
 use std::time::Duration;
@@ -27,6 +32,10 @@ struct Cli {
     #[arg(long, action=ArgAction::SetTrue)]
     use_cli_fallback: bool,
 
+    /// Bearer token for authenticated/hosted Ollama endpoints. Falls back to OLLAMA_API_KEY.
+    #[arg(long)]
+    bearer_token: Option<String>,
+
     #[command(subcommand)]
     command: Option<Cmd>,
 }
@@ -54,6 +63,67 @@ enum Cmd {
         #[arg(long, action=ArgAction::SetTrue)]
         overwrite: bool,
     },
+    /// Inspect a model's Modelfile, parameters, template, and details
+    Show {
+        /// Model to inspect (as shown by `ollama list`)
+        #[arg(long)]
+        model: String,
+    },
+    /// Rename while overriding parameters (num_ctx, system prompt, temperature) via /api/create
+    Derive {
+        /// Source model to derive from
+        #[arg(long)]
+        from: String,
+        /// New model name
+        #[arg(long)]
+        to: String,
+        /// Override context length, e.g. 8192
+        #[arg(long)]
+        num_ctx: Option<u32>,
+        /// Bake in a system prompt
+        #[arg(long)]
+        system: Option<String>,
+        /// Override sampling temperature
+        #[arg(long)]
+        temperature: Option<f32>,
+    },
+    /// Pull a model from the registry, with a live download progress bar
+    Pull {
+        /// Model to pull (as you'd pass to `ollama pull`)
+        #[arg(long)]
+        model: String,
+    },
+    /// Start, stop, or check the Ollama service
+    Service {
+        #[command(subcommand)]
+        action: ServiceAction,
+    },
+    /// Rename many models at once via a regex substitution
+    BatchRename {
+        /// Regex matched against each existing model name
+        #[arg(long)]
+        pattern: String,
+        /// Replacement text (supports $1 capture-group references)
+        #[arg(long)]
+        replacement: String,
+        /// Delete each original after its copy succeeds (acts like move)
+        #[arg(long, action=ArgAction::SetTrue)]
+        delete_original: bool,
+        /// Print the planned old -> new table without making any changes
+        #[arg(long, action=ArgAction::SetTrue)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum ServiceAction {
+    /// Start the registered Ollama service, or spawn `ollama serve` if none is registered
+    Start,
+    /// Stop the registered Ollama service, or kill a spawned `ollama serve` process
+    Stop,
+    /// Report whether the registered Ollama service is running; if no service is
+    /// registered, report whether the Ollama API is currently reachable
+    Status,
 }
 
 #[derive(Deserialize, Debug)]
@@ -80,6 +150,28 @@ struct RunningModel {
     name: Option<String>,
 }
 
+#[derive(Deserialize, Debug, Default)]
+struct ShowResponse {
+    #[serde(default)]
+    modelfile: String,
+    #[serde(default)]
+    parameters: String,
+    #[serde(default)]
+    template: String,
+    #[serde(default)]
+    details: Option<ShowDetails>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct ShowDetails {
+    #[serde(default)]
+    family: String,
+    #[serde(default)]
+    parameter_size: String,
+    #[serde(default)]
+    quantization_level: String,
+}
+
 fn main() {
     if let Err(e) = run_app() {
         eprintln!("\n{}", style(format!("Error: {:?}", e)).red().bold());
@@ -90,22 +182,33 @@ fn main() {
 fn run_app() -> Result<()> {
     let cli = Cli::parse();
     let base = pick_base_url(cli.host.as_deref());
+    let token = pick_bearer_token(cli.bearer_token.as_deref());
     let client = Client::builder()
         .timeout(Duration::from_secs(10)) // fast fail for normal calls
+        .default_headers(auth_headers(token.as_deref())?)
         .build()?;
 
+    // `service` manages Ollama itself, so it must not trigger the usual
+    // auto-start-and-wait dance that the other subcommands rely on.
+    if let Some(Cmd::Service { action }) = &cli.command {
+        return match action {
+            ServiceAction::Start => service_start(),
+            ServiceAction::Stop => service_stop(),
+            ServiceAction::Status => service_status(&client, &base),
+        };
+    }
+
     ensure_ollama_is_running(&client, &base)?;
 
-    if let Some(Cmd::Rename {
-        from,
-        to,
-        delete_original,
-        force,
-        dry_run,
-        overwrite,
-    }) = cli.command
-    {
-        run_non_interactive(
+    match cli.command {
+        Some(Cmd::Rename {
+            from,
+            to,
+            delete_original,
+            force,
+            dry_run,
+            overwrite,
+        }) => run_non_interactive(
             &client,
             &base,
             &from,
@@ -115,9 +218,55 @@ fn run_app() -> Result<()> {
             dry_run,
             cli.use_cli_fallback,
             overwrite,
-        )
-    } else {
-        run_interactive(&client, &base, cli.use_cli_fallback)
+        ),
+        Some(Cmd::Show { model }) => {
+            let resp = show_model(&client, &base, &model)?;
+            print_show(&model, &resp);
+            Ok(())
+        }
+        Some(Cmd::Derive {
+            from,
+            to,
+            num_ctx,
+            system,
+            temperature,
+        }) => {
+            validate_model_name(&to).map_err(|e| anyhow::anyhow!(e))?;
+            println!(
+                "{} {} -> {}",
+                style("Deriving").cyan().bold(),
+                style(&from).yellow(),
+                style(&to).yellow()
+            );
+            let status = derive_model(
+                &client,
+                &base,
+                &from,
+                &to,
+                num_ctx,
+                system.as_deref(),
+                temperature,
+            )?;
+            println!("{} ({})", style("Derive OK.").green(), status);
+            Ok(())
+        }
+        Some(Cmd::Pull { model }) => pull_model(&client, &base, &model, cli.use_cli_fallback),
+        Some(Cmd::Service { .. }) => unreachable!("handled above"),
+        Some(Cmd::BatchRename {
+            pattern,
+            replacement,
+            delete_original,
+            dry_run,
+        }) => run_batch_rename(
+            &client,
+            &base,
+            &pattern,
+            &replacement,
+            delete_original,
+            dry_run,
+            cli.use_cli_fallback,
+        ),
+        None => run_interactive(&client, &base, cli.use_cli_fallback),
     }
 }
 
@@ -146,6 +295,25 @@ fn pick_base_url(arg_host: Option<&str>) -> String {
     }
 }
 
+fn pick_bearer_token(arg_token: Option<&str>) -> Option<String> {
+    // Priority: --bearer-token > OLLAMA_API_KEY > none
+    arg_token
+        .map(|s| s.to_string())
+        .or_else(|| env::var("OLLAMA_API_KEY").ok())
+        .filter(|s| !s.is_empty())
+}
+
+fn auth_headers(token: Option<&str>) -> Result<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    if let Some(token) = token {
+        let mut value = HeaderValue::from_str(&format!("Bearer {}", token))
+            .context("Bearer token contains invalid header characters")?;
+        value.set_sensitive(true);
+        headers.insert(AUTHORIZATION, value);
+    }
+    Ok(headers)
+}
+
 fn run_interactive(client: &Client, base: &str, use_cli_fallback: bool) -> Result<()> {
     let theme = ColorfulTheme::default();
     println!(
@@ -176,6 +344,18 @@ fn run_interactive(client: &Client, base: &str, use_cli_fallback: bool) -> Resul
     let chosen = &models[idx];
     println!("Selected: {}", style(&chosen.name).green());
 
+    if Confirm::with_theme(&theme)
+        .with_prompt("View its Modelfile/parameters before renaming?")
+        .default(false)
+        .interact()?
+    {
+        match show_model(client, base, &chosen.name) {
+            Ok(resp) => print_show(&chosen.name, &resp),
+            Err(e) => eprintln!("{}", style(format!("Failed to fetch details: {:?}", e)).red()),
+        }
+        println!();
+    }
+
     let suggested = suggest_simple_name(&chosen.name);
     let new_name: String = Input::with_theme(&theme)
         .with_prompt("New model name")
@@ -301,6 +481,192 @@ fn run_non_interactive(
     Ok(())
 }
 
+enum BatchOutcome {
+    Renamed,
+    Skipped,
+}
+
+fn run_batch_rename(
+    client: &Client,
+    base: &str,
+    pattern: &str,
+    replacement: &str,
+    delete_original: bool,
+    dry_run: bool,
+    use_cli_fallback: bool,
+) -> Result<()> {
+    let re = Regex::new(pattern).context("Invalid regex pattern")?;
+
+    let models = list_models(client, base).context("Failed to list models. Is Ollama running?")?;
+    let plan: Vec<(String, String)> = models
+        .iter()
+        .filter(|m| re.is_match(&m.name))
+        .filter_map(|m| {
+            let new_name = re.replace(&m.name, replacement).into_owned();
+            (new_name != m.name).then_some((m.name.clone(), new_name))
+        })
+        .collect();
+
+    if plan.is_empty() {
+        println!("{}", style("No models match the pattern.").yellow());
+        return Ok(());
+    }
+
+    println!("{}", style("Planned renames:").bold());
+    for (old, new) in &plan {
+        println!("  {} -> {}", style(old).yellow(), style(new).green());
+    }
+
+    let mut dest_counts: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    for (_, new) in &plan {
+        *dest_counts.entry(new.as_str()).or_insert(0) += 1;
+    }
+    let duplicate_dests: Vec<&str> = dest_counts
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(dest, _)| dest)
+        .collect();
+    if !duplicate_dests.is_empty() {
+        println!(
+            "\n{}",
+            style("Multiple source models map to the same destination name:")
+                .red()
+                .bold()
+        );
+        for dest in &duplicate_dests {
+            let sources: Vec<&str> = plan
+                .iter()
+                .filter(|(_, new)| new.as_str() == *dest)
+                .map(|(old, _)| old.as_str())
+                .collect();
+            println!("  {} <- {}", style(dest).red(), sources.join(", "));
+        }
+        bail!("Refusing to proceed: pattern/replacement produces duplicate destination names. Adjust --pattern/--replacement and try again.");
+    }
+
+    // Guard against rename chains: a destination that is itself one of the
+    // plan's (not-yet-renamed) sources would get clobbered by its own rename
+    // before its turn comes up, since `rename_one` treats "destination already
+    // exists" as an overwrite candidate.
+    let plan_sources: std::collections::HashSet<&str> =
+        plan.iter().map(|(old, _)| old.as_str()).collect();
+    let chained: Vec<(&str, &str)> = plan
+        .iter()
+        .filter(|(_, new)| plan_sources.contains(new.as_str()))
+        .map(|(old, new)| (old.as_str(), new.as_str()))
+        .collect();
+    if !chained.is_empty() {
+        println!(
+            "\n{}",
+            style("Some destinations are themselves sources in this batch:")
+                .red()
+                .bold()
+        );
+        for (old, new) in &chained {
+            println!(
+                "  {} -> {} (but '{}' is also being renamed in this batch)",
+                style(old).red(),
+                style(new).red(),
+                new
+            );
+        }
+        bail!("Refusing to proceed: this batch contains a rename chain. Run it in separate passes so no destination is also a pending source.");
+    }
+
+    if dry_run {
+        println!("{}", style("[dry-run] No changes made.").yellow());
+        return Ok(());
+    }
+
+    let theme = ColorfulTheme::default();
+    let proceed = Confirm::with_theme(&theme)
+        .with_prompt(format!("Rename {} model(s) as shown above?", plan.len()))
+        .default(false)
+        .interact()?;
+    if !proceed {
+        println!("{}", style("Aborted.").yellow());
+        return Ok(());
+    }
+
+    let mut ok = 0usize;
+    let mut skipped = 0usize;
+    let mut failed = 0usize;
+    for (old, new) in &plan {
+        match rename_one(client, base, &theme, old, new, delete_original, use_cli_fallback) {
+            Ok(BatchOutcome::Renamed) => {
+                println!("{} {} -> {}", style("OK").green(), old, new);
+                ok += 1;
+            }
+            Ok(BatchOutcome::Skipped) => {
+                println!("{} {} -> {}", style("SKIPPED").yellow(), old, new);
+                skipped += 1;
+            }
+            Err(e) => {
+                eprintln!("{} {} -> {}: {:?}", style("FAILED").red(), old, new, e);
+                failed += 1;
+            }
+        }
+    }
+
+    println!(
+        "\n{} {} succeeded, {} skipped, {} failed",
+        style("Summary:").bold(),
+        style(ok).green(),
+        style(skipped).yellow(),
+        style(failed).red()
+    );
+    Ok(())
+}
+
+fn rename_one(
+    client: &Client,
+    base: &str,
+    theme: &ColorfulTheme,
+    from: &str,
+    to: &str,
+    delete_original: bool,
+    use_cli_fallback: bool,
+) -> Result<BatchOutcome> {
+    validate_model_name(to).map_err(|e| anyhow::anyhow!(e))?;
+
+    if model_exists(client, base, to)? {
+        let overwrite = Confirm::with_theme(theme)
+            .with_prompt(format!(
+                "'{}' already exists. Overwrite (delete it first)?",
+                to
+            ))
+            .default(false)
+            .interact()?;
+        if !overwrite {
+            return Ok(BatchOutcome::Skipped);
+        }
+        delete_model(client, base, to, use_cli_fallback)
+            .with_context(|| format!("Failed to delete existing destination '{}'", to))?;
+    }
+
+    copy_model(client, base, from, to, use_cli_fallback)
+        .with_context(|| format!("Copy failed from '{}' to '{}'", from, to))?;
+
+    if delete_original {
+        if model_is_running(client, base, from).unwrap_or(false) {
+            let proceed = Confirm::with_theme(theme)
+                .with_prompt(format!(
+                    "'{}' appears loaded (`ollama ps`). Delete anyway?",
+                    from
+                ))
+                .default(false)
+                .interact()?;
+            if !proceed {
+                return Ok(BatchOutcome::Renamed);
+            }
+        }
+        delete_model(client, base, from, use_cli_fallback)
+            .with_context(|| format!("Failed to delete '{}'", from))?;
+    }
+
+    Ok(BatchOutcome::Renamed)
+}
+
 fn validate_model_name(s: &str) -> std::result::Result<(), String> {
     // Require non-empty path segments; optional :tag
     let re =
@@ -364,7 +730,7 @@ fn ensure_ollama_is_running(client: &Client, base: &str) -> Result<()> {
                 "{}",
                 style("Ollama CLI found. Attempting to start the service...").green()
             );
-            start_ollama_service()?;
+            service_start()?;
 
             println!("Waiting for Ollama to start...");
             for _ in 0..30 {
@@ -391,33 +757,154 @@ fn is_ollama_api_running(client: &Client, base: &str) -> bool {
         .is_ok()
 }
 
-fn start_ollama_service() -> Result<()> {
+// The label Ollama's installers register the service under (the Windows
+// installer names the SCM service "Ollama"; systemd/launchd backends derive
+// their own qualified unit/label from the same name).
+fn ollama_service_label() -> Result<ServiceLabel> {
+    "ollama".parse().context("Invalid service label")
+}
+
+fn native_service_manager() -> Result<Box<dyn ServiceManager>> {
+    <dyn ServiceManager>::native().context("No native service manager detected")
+}
+
+fn service_start() -> Result<()> {
+    let label = ollama_service_label()?;
+    let started = native_service_manager().and_then(|mgr| {
+        mgr.start(ServiceStartCtx {
+            label: label.clone(),
+        })
+        .context("Failed to start the registered Ollama service")
+    });
+
+    match started {
+        Ok(()) => {
+            println!(
+                "{}",
+                style("Started Ollama via the system service manager.").green()
+            );
+            Ok(())
+        }
+        Err(e) => {
+            println!(
+                "{}",
+                style(format!(
+                    "No registered Ollama service ({e:#}); spawning `ollama serve` directly."
+                ))
+                .yellow()
+            );
+            spawn_ollama_serve()
+        }
+    }
+}
+
+fn service_stop() -> Result<()> {
+    let label = ollama_service_label()?;
+    let stopped = native_service_manager().and_then(|mgr| {
+        mgr.stop(ServiceStopCtx {
+            label: label.clone(),
+        })
+        .context("Failed to stop the registered Ollama service")
+    });
+
+    match stopped {
+        Ok(()) => {
+            println!(
+                "{}",
+                style("Stopped Ollama via the system service manager.").green()
+            );
+            Ok(())
+        }
+        Err(e) => {
+            println!(
+                "{}",
+                style(format!(
+                    "No registered Ollama service ({e:#}); killing any spawned `ollama serve` process."
+                ))
+                .yellow()
+            );
+            kill_ollama_serve()
+        }
+    }
+}
+
+fn service_status(client: &Client, base: &str) -> Result<()> {
+    let label = ollama_service_label()?;
+    let status = native_service_manager().and_then(|mgr| {
+        mgr.status(ServiceStatusCtx {
+            label: label.clone(),
+        })
+        .context("Failed to query the registered Ollama service")
+    });
+
+    match status {
+        Ok(ServiceStatus::Running) => {
+            println!(
+                "{}",
+                style("Ollama is registered as a system service and is running.").green()
+            );
+        }
+        Ok(ServiceStatus::Stopped(_)) => {
+            println!(
+                "{}",
+                style("Ollama is registered as a system service but is not running.").yellow()
+            );
+        }
+        Ok(ServiceStatus::NotInstalled) | Err(_) => {
+            // No registered service for this platform/label; fall back to reporting
+            // whether a loose `ollama serve` process is reachable via the API.
+            if is_ollama_api_running(client, base) {
+                println!(
+                    "{}",
+                    style("No registered service found; Ollama API is reachable (running as a loose `ollama serve` process).")
+                        .green()
+                );
+            } else {
+                println!(
+                    "{}",
+                    style("No registered service found, and the Ollama API is not reachable.")
+                        .red()
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn spawn_ollama_serve() -> Result<()> {
+    Command::new("ollama")
+        .arg("serve")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .context("Failed to spawn `ollama serve`")?;
+    Ok(())
+}
+
+fn kill_ollama_serve() -> Result<()> {
     if cfg!(target_os = "windows") {
-        // Try the Windows service first (name 'Ollama' from the official installer)
-        if Command::new("sc")
-            .args(["start", "Ollama"])
+        // Scope the kill to `ollama serve` processes by command line, the way
+        // the Unix branch does with `pkill -f` — a blanket `taskkill /IM
+        // ollama.exe` would also kill an unrelated `ollama run` session.
+        Command::new("wmic")
+            .args([
+                "process",
+                "where",
+                "name='ollama.exe' and commandline like '%serve%'",
+                "call",
+                "terminate",
+            ])
             .stdout(Stdio::null())
             .stderr(Stdio::null())
             .status()
-            .map(|s| s.success())
-            .unwrap_or(false)
-        {
-            return Ok(());
-        }
-        // Fallback: spawn a new window running `ollama serve`
-        Command::new("cmd")
-            .args(&["/C", "start", "ollama", "serve"])
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .spawn()
-            .context("Failed to start Ollama on Windows.")?;
+            .context("Failed to invoke `wmic`")?;
     } else {
-        Command::new("ollama")
-            .arg("serve")
+        Command::new("pkill")
+            .args(["-f", "ollama serve"])
             .stdout(Stdio::null())
             .stderr(Stdio::null())
-            .spawn()
-            .context("Failed to start Ollama.")?;
+            .status()
+            .context("Failed to invoke `pkill`")?;
     }
     Ok(())
 }
@@ -518,6 +1005,248 @@ fn delete_model(client: &Client, base: &str, name: &str, use_cli_fallback: bool)
     }
 }
 
+fn show_model(client: &Client, base: &str, name: &str) -> Result<ShowResponse> {
+    // POST /api/show {"name": <model>} -> {modelfile, parameters, template, details}
+    let url = api_url(base, "/api/show");
+    let resp = client
+        .post(&url)
+        .json(&json!({"name": name}))
+        .send()
+        .context("POST /api/show failed")?;
+    if !resp.status().is_success() {
+        bail!("POST /api/show -> HTTP {}", resp.status());
+    }
+    resp.json().context("Decode /api/show JSON")
+}
+
+fn print_show(name: &str, resp: &ShowResponse) {
+    println!("{}", style(format!("=== {} ===", name)).bold().cyan());
+
+    if let Some(details) = &resp.details {
+        println!("{}", style("Details:").bold());
+        println!("  family:      {}", details.family);
+        println!("  param size:  {}", details.parameter_size);
+        println!("  quantize:    {}", details.quantization_level);
+    }
+
+    if !resp.template.is_empty() {
+        println!("\n{}", style("Template:").bold());
+        println!("{}", resp.template);
+    }
+
+    if !resp.parameters.is_empty() {
+        println!("\n{}", style("Parameters:").bold());
+        println!("{}", resp.parameters);
+    }
+
+    if !resp.modelfile.is_empty() {
+        println!("\n{}", style("Modelfile:").bold());
+        println!("{}", resp.modelfile);
+    }
+}
+
+fn derive_model(
+    client: &Client,
+    base: &str,
+    from: &str,
+    to: &str,
+    num_ctx: Option<u32>,
+    system: Option<&str>,
+    temperature: Option<f32>,
+) -> Result<String> {
+    let mut params = serde_json::Map::new();
+    if let Some(n) = num_ctx {
+        params.insert("num_ctx".to_string(), json!(n));
+    }
+    if let Some(t) = temperature {
+        params.insert("temperature".to_string(), json!(t));
+    }
+
+    let mut payload = json!({"model": to, "from": from});
+    if !params.is_empty() {
+        payload["parameters"] = Value::Object(params);
+    }
+    if let Some(sys) = system {
+        payload["system"] = json!(sys);
+    }
+
+    match create_model_via_api(client, base, &payload) {
+        Ok(status) => Ok(status),
+        Err(e) => {
+            // Older Ollama versions only accept a raw modelfile string rather than
+            // structured "from"/"parameters" fields.
+            let modelfile = build_legacy_modelfile(from, num_ctx, system, temperature);
+            create_model_via_api(client, base, &json!({"model": to, "modelfile": modelfile}))
+                .map_err(|_| e)
+        }
+    }
+}
+
+fn build_legacy_modelfile(
+    from: &str,
+    num_ctx: Option<u32>,
+    system: Option<&str>,
+    temperature: Option<f32>,
+) -> String {
+    let mut s = format!("FROM {}\n", from);
+    if let Some(n) = num_ctx {
+        s.push_str(&format!("PARAMETER num_ctx {}\n", n));
+    }
+    if let Some(t) = temperature {
+        s.push_str(&format!("PARAMETER temperature {}\n", t));
+    }
+    if let Some(sys) = system {
+        s.push_str(&format!("SYSTEM \"\"\"{}\"\"\"\n", sys));
+    }
+    s
+}
+
+// POST a /api/create payload and stream the newline-delimited JSON status
+// objects it returns, bailing on the first line with an "error" field and
+// returning the last seen status (expected to be "success").
+fn create_model_via_api(client: &Client, base: &str, payload: &Value) -> Result<String> {
+    let url = api_url(base, "/api/create");
+    let resp = client
+        .post(&url)
+        .json(payload)
+        .timeout(Duration::from_secs(60 * 60))
+        .send()
+        .context("POST /api/create failed")?;
+    if !resp.status().is_success() {
+        bail!("POST /api/create -> HTTP {}", resp.status());
+    }
+
+    let mut last_status = String::new();
+    for line in BufReader::new(resp).lines() {
+        let line = line.context("Reading /api/create stream")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let v: Value = serde_json::from_str(&line)
+            .with_context(|| format!("Decode /api/create line: {}", line))?;
+        if let Some(err) = v.get("error").and_then(|e| e.as_str()) {
+            bail!("/api/create error: {}", err);
+        }
+        if let Some(status) = v.get("status").and_then(|s| s.as_str()) {
+            last_status = status.to_string();
+            if status == "success" {
+                return Ok(last_status);
+            }
+        }
+    }
+    bail!(
+        "/api/create stream ended without a success status (last status: '{}')",
+        last_status
+    );
+}
+
+fn pull_model(client: &Client, base: &str, model: &str, use_cli_fallback: bool) -> Result<()> {
+    let url = api_url(base, "/api/pull");
+    let res = client
+        .post(&url)
+        .json(&json!({"model": model, "stream": true}))
+        .timeout(Duration::from_secs(60 * 60))
+        .send();
+
+    let resp = match res {
+        Ok(resp) if resp.status().is_success() => resp,
+        Ok(resp) => {
+            let status = resp.status();
+            if use_cli_fallback {
+                eprintln!("API pull failed ({}). Falling back to CLI...", status);
+                return cli_pull(model);
+            }
+            bail!("POST /api/pull -> HTTP {}", status);
+        }
+        Err(e) => {
+            if use_cli_fallback {
+                eprintln!("API pull error: {}. Falling back to CLI...", e);
+                return cli_pull(model);
+            }
+            return Err(e).context("POST /api/pull failed");
+        }
+    };
+
+    let multi = MultiProgress::new();
+    let bar_style = ProgressStyle::with_template("{prefix:.cyan} [{bar:30}] {msg}")
+        .unwrap()
+        .progress_chars("=> ");
+    let mut bars: std::collections::HashMap<String, ProgressBar> = std::collections::HashMap::new();
+
+    for line in BufReader::new(resp).lines() {
+        let line = line.context("Reading /api/pull stream")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let v: Value = serde_json::from_str(&line)
+            .with_context(|| format!("Decode /api/pull line: {}", line))?;
+        if let Some(err) = v.get("error").and_then(|e| e.as_str()) {
+            for bar in bars.values() {
+                bar.abandon();
+            }
+            bail!("/api/pull error: {}", err);
+        }
+
+        match v.get("status").and_then(|s| s.as_str()).unwrap_or("") {
+            "success" => {
+                for bar in bars.values() {
+                    bar.finish_and_clear();
+                }
+                println!("{}", style("Pull OK.").green());
+                return Ok(());
+            }
+            "downloading" => {
+                let digest = v
+                    .get("digest")
+                    .and_then(|d| d.as_str())
+                    .unwrap_or("layer")
+                    .to_string();
+                let total = v.get("total").and_then(|t| t.as_u64()).unwrap_or(0);
+                let completed = v.get("completed").and_then(|c| c.as_u64()).unwrap_or(0);
+
+                let bar = bars.entry(digest.clone()).or_insert_with(|| {
+                    let b = multi.add(ProgressBar::new(total));
+                    b.set_style(bar_style.clone());
+                    b.set_prefix(short_digest(&digest));
+                    b
+                });
+                if total > 0 {
+                    bar.set_length(total);
+                }
+                bar.set_position(completed);
+                bar.set_message(format!("{} / {}", format_size(completed), format_size(total)));
+            }
+            status => {
+                // Print above the live bars via MultiProgress rather than a bare
+                // println!, which would race indicatif's cursor-controlled redraw.
+                multi
+                    .println(status)
+                    .context("Failed to print /api/pull status")?;
+            }
+        }
+    }
+
+    for bar in bars.values() {
+        bar.abandon();
+    }
+    bail!("/api/pull stream ended without a success status");
+}
+
+fn short_digest(digest: &str) -> String {
+    digest.trim_start_matches("sha256:").chars().take(12).collect()
+}
+
+fn cli_pull(model: &str) -> Result<()> {
+    let status = Command::new("ollama")
+        .args(["pull", model])
+        .status()
+        .context("Failed to invoke `ollama` binary")?;
+    if !status.success() {
+        bail!("`ollama pull` returned non-zero status");
+    }
+    Ok(())
+}
+
 fn cli_copy(from: &str, to: &str) -> Result<()> {
     let status = Command::new("ollama")
         .args(["cp", from, to])